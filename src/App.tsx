@@ -6,6 +6,7 @@ import {MultiWindowProvider} from './contexts/MultiWindowContext'
 import {PortalManagerProvider} from './components/portals/PortalManager'
 import {router} from './routes/router'
 import {assistantRouter} from './routes/router-assistant'
+import SettingsPage from './pages/assistant/SettingsPage'
 import {RouterProvider} from '@tanstack/react-router'
 import {initializeTranscriptionEventMiddleware} from './middleware/TranscriptionEventMiddleware'
 import {markPerformance} from './utils/performance-profiler'
@@ -22,6 +23,11 @@ function AppContent() {
     markPerformance('app_content_mounted')
   }, [])
 
+  // Settings windows render the preferences page on its own, without navigation
+  if (windowState.windowType === 'settings') {
+    return <SettingsPage />
+  }
+
   return (
     <>
       <RouterProvider router={currentRouter} />
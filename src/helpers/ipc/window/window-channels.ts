@@ -12,6 +12,7 @@ export const WINDOW_HIDE_CHANNEL = 'window:hide'
 export const WINDOW_FOCUS_CHANNEL = 'window:focus'
 export const WINDOW_GET_ALL_CHANNEL = 'window:getAll'
 export const WINDOW_GET_INFO_CHANNEL = 'window:getInfo'
+export const WINDOW_OPEN_SETTINGS_CHANNEL = 'window:openSettings'
 
 // Inter-window communication channels
 export const INTER_WINDOW_MESSAGE_CHANNEL = 'window:interMessage'
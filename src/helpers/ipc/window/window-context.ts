@@ -10,6 +10,7 @@ import {
   WINDOW_FOCUS_CHANNEL,
   WINDOW_GET_ALL_CHANNEL,
   WINDOW_GET_INFO_CHANNEL,
+  WINDOW_OPEN_SETTINGS_CHANNEL,
   INTER_WINDOW_MESSAGE_CHANNEL,
   WINDOW_BROADCAST_CHANNEL,
   WINDOW_STATE_CHANGED_CHANNEL,
@@ -41,6 +42,7 @@ export function exposeWindowContext() {
         focusWindow: (windowId: string) => ipcRenderer.invoke(WINDOW_FOCUS_CHANNEL, windowId),
         getAllWindows: () => ipcRenderer.invoke(WINDOW_GET_ALL_CHANNEL),
         getWindowInfo: (windowId?: string) => ipcRenderer.invoke(WINDOW_GET_INFO_CHANNEL, windowId),
+        openSettingsWindow: () => ipcRenderer.invoke(WINDOW_OPEN_SETTINGS_CHANNEL),
 
         // Inter-window communication
         sendToWindow: (targetWindowId: string, channel: string, ...args: any[]) =>
@@ -22,6 +22,7 @@ import {
   WINDOW_FOCUS_CHANNEL,
   WINDOW_GET_ALL_CHANNEL,
   WINDOW_GET_INFO_CHANNEL,
+  WINDOW_OPEN_SETTINGS_CHANNEL,
   INTER_WINDOW_MESSAGE_CHANNEL,
   WINDOW_BROADCAST_CHANNEL,
   WINDOW_GET_REF_CHANNEL
@@ -82,10 +83,18 @@ export function addWindowEventListeners() {
   ipcMain.handle(
     WINDOW_CREATE_CHANNEL,
     (_event, type: WindowType, config?: Partial<WindowConfig>) => {
+      // Only one settings window may exist; reuse it instead of stacking duplicates
+      if (type === 'settings') {
+        return windowManager.openSettingsWindow()
+      }
       return windowManager.createWindow(type, config)
     }
   )
 
+  ipcMain.handle(WINDOW_OPEN_SETTINGS_CHANNEL, () => {
+    return windowManager.openSettingsWindow()
+  })
+
   ipcMain.handle(WINDOW_SHOW_CHANNEL, (_event, windowId: string) => {
     windowManager.showWindow(windowId)
   })
@@ -6,7 +6,8 @@ import {
   Tray,
   Menu,
   nativeImage,
-  globalShortcut
+  globalShortcut,
+  type MenuItemConstructorOptions
 } from 'electron'
 
 import {electronApp, optimizer} from '@electron-toolkit/utils'
@@ -102,6 +103,13 @@ function createTray(windowManager: WindowManager) {
         }
       }
     },
+    {
+      label: 'Preferences…',
+      click: () => {
+        windowManager.openSettingsWindow()
+      }
+    },
+    {type: 'separator'},
     {
       label: 'Quit',
       click: () => {
@@ -128,6 +136,46 @@ function createTray(windowManager: WindowManager) {
   })
 }
 
+function createApplicationMenu(): void {
+  const preferencesItem: MenuItemConstructorOptions = {
+    label: 'Preferences…',
+    accelerator: 'CommandOrControl+,',
+    click: () => {
+      WindowManager.getInstance().openSettingsWindow()
+    }
+  }
+
+  // Mirror Electron's default menu, adding Preferences… where each platform expects it
+  const template: MenuItemConstructorOptions[] = [
+    process.platform === 'darwin'
+      ? {
+          label: app.name,
+          submenu: [
+            {role: 'about'},
+            {type: 'separator'},
+            preferencesItem,
+            {type: 'separator'},
+            {role: 'services'},
+            {type: 'separator'},
+            {role: 'hide'},
+            {role: 'hideOthers'},
+            {role: 'unhide'},
+            {type: 'separator'},
+            {role: 'quit'}
+          ]
+        }
+      : {
+          label: 'File',
+          submenu: [preferencesItem, {type: 'separator'}, {role: 'quit'}]
+        },
+    {role: 'editMenu'},
+    {role: 'viewMenu'},
+    {role: 'windowMenu'}
+  ]
+
+  Menu.setApplicationMenu(Menu.buildFromTemplate(template))
+}
+
 function createWindow(): string {
   const windowManager = WindowManager.getInstance()
 
@@ -171,6 +219,9 @@ app.whenReady().then(async () => {
   // Register global IPC listeners once
   registerListeners()
 
+  // Native app menu, including Preferences… (⌘, or Ctrl+,)
+  createApplicationMenu()
+
   // Register global shortcuts
 
   // Register global shortcut to restore main window
@@ -67,6 +67,21 @@ export default function SettingsPage() {
     }
   }, [])
 
+  // Keep in sync when settings are changed from another window
+  useEffect(() => {
+    const handleStorage = (event: StorageEvent) => {
+      if (event.key !== 'dao-copilot-settings' || !event.newValue) return
+      try {
+        setSettings({...DEFAULT_SETTINGS, ...JSON.parse(event.newValue)})
+      } catch (error) {
+        console.warn('Failed to sync settings from another window:', error)
+      }
+    }
+
+    window.addEventListener('storage', handleStorage)
+    return () => window.removeEventListener('storage', handleStorage)
+  }, [])
+
   const handleSettingChange = (key: string, value: string | boolean) => {
     setSettings(prev => {
       const newSettings = {...prev, [key]: value}
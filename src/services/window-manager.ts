@@ -3,7 +3,7 @@ import {join} from 'path'
 import icon from '../../resources/icon.png'
 import {isDevelopmentEnvironment} from '../utils/env'
 
-export type WindowType = 'main' | 'assistant'
+export type WindowType = 'main' | 'assistant' | 'settings'
 
 export type ComponentType =
   | 'transcription-display'
@@ -113,6 +113,19 @@ export class WindowManager {
       alwaysOnTop: false,
       frame: false,
       transparent: true
+    },
+    // Standalone preferences window: native frame, never transparent or always-on-top
+    settings: {
+      width: 720,
+      height: 560,
+      show: false,
+      resizable: false,
+      minimizable: true,
+      maximizable: false,
+      closable: true,
+      alwaysOnTop: false,
+      frame: true,
+      transparent: false
     }
   }
 
@@ -248,6 +261,19 @@ export class WindowManager {
     }
   }
 
+  /**
+   * Focus the settings window, creating it if it does not exist yet
+   */
+  public openSettingsWindow(): string {
+    const settingsWindows = this.getWindowsByType('settings')
+    if (settingsWindows.length > 0) {
+      this.focusWindow(settingsWindows[0].id)
+      return settingsWindows[0].id
+    }
+
+    return this.createWindow('settings', {show: true})
+  }
+
   public toggleAssistantWithDualFocus(): void {
     const assistantWindows = this.getWindowsByType('assistant')
     const mainWindows = this.getWindowsByType('main')
@@ -40,6 +40,7 @@ declare global {
       focusWindow: (windowId: string) => void;
       getAllWindows: () => Promise<any[]>;
       getWindowInfo: (windowId?: string) => Promise<any>;
+      openSettingsWindow: () => Promise<string>;
       
       // Inter-window communication
       sendToWindow: (targetWindowId: string, channel: string, ...args: any[]) => void;